@@ -3,10 +3,14 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::{convert::TryFrom, fmt};
+use std::{
+    convert::{TryFrom, TryInto},
+    fmt,
+    str::FromStr,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum State {
+pub enum State {
     ALIVE,
     DEAD,
 }
@@ -52,16 +56,343 @@ impl Distribution<State> for Standard {
     }
 }
 
+/// A cellular-automaton transition rule in birth/survival (`B/S`) notation.
+///
+/// The digits after `B` are the neighbor counts that bring a dead cell to life,
+/// the digits after `S` are the counts that let a live cell survive. Conway's
+/// life is `B3/S23`; Highlife is `B36/S23`, Seeds is `B2/S`. Each set is kept as
+/// a `u16` bitmask indexed by neighbor count `0..=8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    birth: u16,
+    survival: u16,
+}
+
+impl Rule {
+    /// Conway's original `B3/S23` rule.
+    pub fn conway() -> Self {
+        Self {
+            birth: 1 << 3,
+            survival: (1 << 2) | (1 << 3),
+        }
+    }
+
+    /// Apply the rule to a cell given its current state and live neighbor count.
+    pub fn apply(&self, current: State, live_neighbors: usize) -> State {
+        let bit = 1 << live_neighbors;
+        match current {
+            State::DEAD => State::from(self.birth & bit != 0),
+            State::ALIVE => State::from(self.survival & bit != 0),
+        }
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::conway()
+    }
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rule::try_from(s)
+    }
+}
+
+impl TryFrom<&str> for Rule {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (birth, survival) = value
+            .split_once('/')
+            .ok_or("Invalid rule, expected birth/survival notation like \"B3/S23\"")?;
+        fn mask(part: &str, prefix: char) -> Result<u16, Error> {
+            let digits = part
+                .strip_prefix(prefix)
+                .or_else(|| part.strip_prefix(prefix.to_ascii_lowercase()))
+                .ok_or_else(|| format!("Invalid rule, expected '{prefix}' prefix"))?;
+            let mut mask = 0u16;
+            for c in digits.chars() {
+                let n = c
+                    .to_digit(10)
+                    .filter(|&n| n <= 8)
+                    .ok_or("Invalid rule, neighbor counts must be digits 0..=8")?;
+                mask |= 1 << n;
+            }
+            Ok(mask)
+        }
+        Ok(Self {
+            birth: mask(birth, 'B')?,
+            survival: mask(survival, 'S')?,
+        })
+    }
+}
+
+/// How neighbor coordinates behave at the edges of the board.
+///
+/// `Bounded` treats off-grid positions as permanently dead, giving a finite
+/// universe. `Toroidal` wraps coordinates modulo the board size so a glider
+/// leaving one edge reappears on the opposite one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    Toroidal,
+}
+
+impl FromStr for Topology {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bounded" => Ok(Topology::Bounded),
+            "toroidal" | "torus" => Ok(Topology::Toroidal),
+            other => Err(format!("Unknown topology {other:?}, expected bounded or toroidal"))?,
+        }
+    }
+}
+
+/// How a cell's neighbors are gathered.
+///
+/// `Moore` is the classic eight immediately adjacent cells. `LineOfSight`
+/// traces each of the eight compass directions outward until the first live
+/// cell (or the grid edge), counting that cell as the neighbor — a "ray-cast"
+/// neighborhood that yields very different dynamics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neighborhood {
+    #[default]
+    Moore,
+    LineOfSight,
+}
+
+impl FromStr for Neighborhood {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "moore" => Ok(Neighborhood::Moore),
+            "line-of-sight" | "los" => Ok(Neighborhood::LineOfSight),
+            other => Err(format!(
+                "Unknown neighborhood {other:?}, expected moore or line-of-sight"
+            ))?,
+        }
+    }
+}
+
+// Neighbor offsets, clockwise starting from straight up.
+pub(crate) const NEIGHBORS: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
 #[derive(Clone)]
 pub struct Board {
     state: Vec<Vec<State>>,
+    topology: Topology,
+    neighborhood: Neighborhood,
+    // Logical coordinate of the top-left cell, so an auto-expanding universe can
+    // keep reporting stable coordinates as it grows.
+    origin: (i64, i64),
 }
 
 impl Board {
     pub fn new(w: usize, h: usize) -> Self {
         let row = vec![State::DEAD; w];
         let s = vec![row; h];
-        Self { state: s }
+        Self {
+            state: s,
+            topology: Topology::default(),
+            neighborhood: Neighborhood::default(),
+            origin: (0, 0),
+        }
+    }
+
+    /// Set the edge [`Topology`] used when gathering neighbors.
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Set the [`Neighborhood`] strategy used when counting live neighbors.
+    pub fn with_neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// The edge [`Topology`] currently in effect.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// The state of the cell at `(y, x)`.
+    pub fn state_at(&self, y: usize, x: usize) -> State {
+        self.state[y][x]
+    }
+
+    /// Overwrite the state of the cell at `(y, x)`.
+    pub fn set(&mut self, y: usize, x: usize, state: State) {
+        self.state[y][x] = state;
+    }
+
+    /// The logical coordinate `(x, y)` of the top-left cell. Grows negative as
+    /// the universe expands past its initial origin.
+    pub fn origin(&self) -> (i64, i64) {
+        self.origin
+    }
+
+    /// Grow the grid by one dead row or column on every side whose outermost
+    /// line currently holds a live cell, shifting [`origin`](Self::origin) to
+    /// keep logical coordinates stable. This gives the pattern room before the
+    /// next step so spaceships never die against a wall.
+    pub fn expand(&mut self) {
+        let (h, w) = (self.height(), self.width());
+        if h == 0 || w == 0 {
+            return;
+        }
+        let alive = |y: usize, x: usize| self.state[y][x] == State::ALIVE;
+        let top = (0..w).any(|x| alive(0, x));
+        let bottom = (0..w).any(|x| alive(h - 1, x));
+        let left = (0..h).any(|y| alive(y, 0));
+        let right = (0..h).any(|y| alive(y, w - 1));
+
+        if top {
+            self.state.insert(0, vec![State::DEAD; w]);
+            self.origin.1 -= 1;
+        }
+        if bottom {
+            self.state.push(vec![State::DEAD; w]);
+        }
+        if left {
+            for row in &mut self.state {
+                row.insert(0, State::DEAD);
+            }
+            self.origin.0 -= 1;
+        }
+        if right {
+            for row in &mut self.state {
+                row.push(State::DEAD);
+            }
+        }
+    }
+
+    /// Like [`next_board_state_with`](Self::next_board_state_with) but grows the
+    /// universe first (see [`expand`](Self::expand)) so live cells never reach a
+    /// boundary.
+    pub fn next_board_state_expanding_with(&self, rule: &Rule) -> Self {
+        let mut grown = self.clone();
+        grown.expand();
+        grown.next_board_state_with(rule)
+    }
+
+    /// Render a `vw × vh` window centered on the live mass (or the grid center
+    /// when empty), padding off-grid positions with dead cells. Useful for
+    /// watching an ever-growing universe through a fixed-size terminal.
+    pub fn viewport(&self, vw: usize, vh: usize) -> String {
+        let (h, w) = (self.height() as i64, self.width() as i64);
+        let (cy, cx) = self.live_bounds().map_or((h / 2, w / 2), |(y0, y1, x0, x1)| {
+            ((y0 + y1) / 2, (x0 + x1) / 2)
+        });
+        let top = cy - vh as i64 / 2;
+        let left = cx - vw as i64 / 2;
+        let mut out = String::with_capacity((vw + 1) * vh);
+        for dy in 0..vh as i64 {
+            for dx in 0..vw as i64 {
+                let (y, x) = (top + dy, left + dx);
+                let alive = (0..h).contains(&y)
+                    && (0..w).contains(&x)
+                    && self.state[y as usize][x as usize] == State::ALIVE;
+                out.push(if alive { '#' } else { '.' });
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Bounding box `(min_y, max_y, min_x, max_x)` of the live cells, or `None`
+    /// when the board is empty.
+    fn live_bounds(&self) -> Option<(i64, i64, i64, i64)> {
+        let mut bounds: Option<(i64, i64, i64, i64)> = None;
+        for (y, row) in self.state.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                if cell == State::ALIVE {
+                    let (y, x) = (y as i64, x as i64);
+                    bounds = Some(match bounds {
+                        Some((y0, y1, x0, x1)) => {
+                            (y0.min(y), y1.max(y), x0.min(x), x1.max(x))
+                        }
+                        None => (y, y, x, x),
+                    });
+                }
+            }
+        }
+        bounds
+    }
+
+    /// Count the live neighbors of `(y, x)` under the active [`Neighborhood`].
+    fn live_neighbors(&self, y: usize, x: usize) -> usize {
+        match self.neighborhood {
+            Neighborhood::Moore => NEIGHBORS
+                .iter()
+                .filter_map(|&(dy, dx)| self.neighbor(y, x, dy, dx))
+                .filter(|&(ny, nx)| self.state[ny][nx] == State::ALIVE)
+                .count(),
+            Neighborhood::LineOfSight => NEIGHBORS
+                .iter()
+                .filter(|&&(dy, dx)| self.ray_hits_live(y, x, dy, dx))
+                .count(),
+        }
+    }
+
+    /// Walk outward from `(y, x)` in direction `(dy, dx)`, past any dead cells,
+    /// and report whether a live cell is found before the grid edge.
+    fn ray_hits_live(&self, y: usize, x: usize, dy: isize, dx: isize) -> bool {
+        let (mut y, mut x) = (y as isize, x as isize);
+        loop {
+            y += dy;
+            x += dx;
+            match usize::try_from(y)
+                .ok()
+                .zip(usize::try_from(x).ok())
+                .and_then(|(y, x)| self.state.get(y).and_then(|row| row.get(x)))
+            {
+                Some(&cell) => {
+                    if cell == State::ALIVE {
+                        return true;
+                    }
+                }
+                None => return false,
+            }
+        }
+    }
+
+    /// Resolve the neighbor at offset `(dy, dx)` from `(y, x)`, honoring the
+    /// board's [`Topology`]. Returns `None` for off-grid positions under
+    /// [`Topology::Bounded`].
+    fn neighbor(&self, y: usize, x: usize, dy: isize, dx: isize) -> Option<(usize, usize)> {
+        let (h, w) = (self.height(), self.width());
+        match self.topology {
+            Topology::Bounded => {
+                let ny = (y as isize + dy).try_into().ok().filter(|&ny| ny < h)?;
+                let nx = (x as isize + dx).try_into().ok().filter(|&nx| nx < w)?;
+                Some((ny, nx))
+            }
+            Topology::Toroidal => {
+                if w == 0 || h == 0 {
+                    return None;
+                }
+                let ny = (y + h).wrapping_add_signed(dy) % h;
+                let nx = (x + w).wrapping_add_signed(dx) % w;
+                Some((ny, nx))
+            }
+        }
     }
 
     pub fn width(&self) -> usize {
@@ -82,43 +413,18 @@ impl Board {
         }
     }
 
-    pub fn next_board_state(&self) -> Self {
-        // Rules:
-        // 1. Any live cell with 0 or 1 live neighbors becomes dead, because of underpopulation
-        // 2. Any live cell with 2 or 3 live neighbors stays alive, because its neighborhood is just right
-        // 3. Any live cell with more than 3 live neighbors becomes dead, because of overpopulation
-        // 4. Any dead cell with exactly 3 live neighbors becomes alive, by reproduction
+    /// Step the board forward using an arbitrary birth/survival [`Rule`]. Under
+    /// Conway's `B3/S23` the classic rules apply:
+    /// 1. Any live cell with 0 or 1 live neighbors becomes dead, because of underpopulation
+    /// 2. Any live cell with 2 or 3 live neighbors stays alive, because its neighborhood is just right
+    /// 3. Any live cell with more than 3 live neighbors becomes dead, because of overpopulation
+    /// 4. Any dead cell with exactly 3 live neighbors becomes alive, by reproduction
+    pub fn next_board_state_with(&self, rule: &Rule) -> Self {
         let mut new_board = self.clone();
         for y in 0..self.height() {
             for x in 0..self.width() {
-                let prev_row = y.checked_sub(1);
-                let next_row = y.checked_add(1);
-                let prev_col = x.checked_sub(1);
-                let next_col = x.checked_add(1);
-                // Clockwise
-                let neighbors = [
-                    prev_row.zip(Some(x)),
-                    prev_row.zip(next_col),
-                    Some(y).zip(next_col),
-                    next_row.zip(next_col),
-                    next_row.zip(Some(x)),
-                    next_row.zip(prev_col),
-                    Some(y).zip(prev_col),
-                    prev_row.zip(prev_col),
-                ];
-                let live_counts = neighbors
-                    .iter()
-                    .filter_map(|pos| {
-                        pos.and_then(|(y, x)| self.state.get(y).and_then(|row| row.get(x)))
-                    })
-                    .filter(|&&cell| cell == State::ALIVE)
-                    .count();
-                match (self.state[y][x], live_counts) {
-                    (State::ALIVE, 0 | 1) => new_board.state[y][x] = State::DEAD,
-                    (State::ALIVE, count) if count > 3 => new_board.state[y][x] = State::DEAD,
-                    (State::DEAD, 3) => new_board.state[y][x] = State::ALIVE,
-                    _ => (),
-                }
+                let live_counts = self.live_neighbors(y, x);
+                new_board.state[y][x] = rule.apply(self.state[y][x], live_counts);
             }
         }
         new_board
@@ -132,6 +438,9 @@ impl<const W: usize, const H: usize> From<[[bool; W]; H]> for Board {
                 .iter()
                 .map(|r| r.iter().copied().map(State::from).collect())
                 .collect(),
+            topology: Topology::default(),
+            neighborhood: Neighborhood::default(),
+            origin: (0, 0),
         }
     }
 }
@@ -148,7 +457,12 @@ impl TryFrom<&str> for Board {
             .lines()
             .map(|line| line.bytes().map(State::from).collect())
             .collect();
-        Ok(Self { state: s })
+        Ok(Self {
+            state: s,
+            topology: Topology::default(),
+            neighborhood: Neighborhood::default(),
+            origin: (0, 0),
+        })
     }
 }
 
@@ -230,7 +544,7 @@ mod tests {
 
     #[test]
     fn next_board_state_edge_check() -> Result<()> {
-        let board = Board::try_from("#")?.next_board_state();
+        let board = Board::try_from("#")?.next_board_state_with(&Rule::conway());
         assert_eq!(board.to_string().trim(), ".");
         Ok(())
     }
@@ -241,7 +555,7 @@ mod tests {
 #..
 #..
 ...";
-        let board = Board::try_from(s)?.next_board_state();
+        let board = Board::try_from(s)?.next_board_state_with(&Rule::conway());
         insta::assert_display_snapshot!(board, @r###"
         ...
         ...
@@ -257,7 +571,7 @@ mod tests {
 ##.
 ##.
 ...";
-        let board = Board::try_from(s)?.next_board_state();
+        let board = Board::try_from(s)?.next_board_state_with(&Rule::conway());
         insta::assert_display_snapshot!(board, @r###"
         ...
         ##.
@@ -272,7 +586,7 @@ mod tests {
         let s = r"#.#
 ###
 #.#";
-        let board = Board::try_from(s)?.next_board_state();
+        let board = Board::try_from(s)?.next_board_state_with(&Rule::conway());
         insta::assert_display_snapshot!(board, @r###"
         #.#
         #.#
@@ -281,6 +595,93 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn rule_parses_conway() -> Result<()> {
+        assert_eq!(Rule::try_from("B3/S23")?, Rule::conway());
+        Ok(())
+    }
+
+    #[test]
+    fn rule_rejects_malformed() {
+        assert!(Rule::try_from("3/23").is_err());
+        assert!(Rule::try_from("B3").is_err());
+        assert!(Rule::try_from("Bx/S23").is_err());
+    }
+
+    #[test]
+    fn highlife_steps_a_ring() -> Result<()> {
+        // A ring of eight live cells under Highlife (B36/S23). The dead center has
+        // eight live neighbors and no dead cell has six, so this step comes out
+        // the same as under Conway's B3/S23.
+        let s = r".....
+.###.
+.#.#.
+.###.
+.....";
+        let highlife = Rule::try_from("B36/S23")?;
+        let board = Board::try_from(s)?.next_board_state_with(&highlife);
+        insta::assert_display_snapshot!(board, @r###"
+        ..#..
+        .#.#.
+        #...#
+        .#.#.
+        ..#..
+        "###);
+        Ok(())
+    }
+
+    #[test]
+    fn expand_grows_edges_and_tracks_origin() -> Result<()> {
+        let mut board = Board::try_from("#")?;
+        board.expand();
+        assert_eq!(board.origin(), (-1, -1));
+        assert_eq!((board.width(), board.height()), (3, 3));
+        assert_eq!(board.to_string(), "...\n.#.\n...\n");
+        Ok(())
+    }
+
+    #[test]
+    fn viewport_centers_on_live_mass() {
+        let board = Board::from([[true]]);
+        assert_eq!(board.viewport(3, 3), "...\n.#.\n...\n");
+    }
+
+    #[test]
+    fn line_of_sight_sees_past_dead_cells() -> Result<()> {
+        // Four live cells sit beyond the immediate neighborhood of the center.
+        let s = r"..#..
+.....
+#...#
+.....
+..#..";
+        assert_eq!(Board::try_from(s)?.live_neighbors(2, 2), 0);
+        assert_eq!(
+            Board::try_from(s)?
+                .with_neighborhood(Neighborhood::LineOfSight)
+                .live_neighbors(2, 2),
+            4
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn toroidal_wraps_across_edges() -> Result<()> {
+        // A column blinker on a 3×3 torus: vertical neighbors wrap so every
+        // cell ends up with enough live neighbors to be alive next step.
+        let s = r".#.
+.#.
+.#.";
+        let board = Board::try_from(s)?
+            .with_topology(Topology::Toroidal)
+            .next_board_state_with(&Rule::conway());
+        insta::assert_display_snapshot!(board, @r###"
+        ###
+        ###
+        ###
+        "###);
+        Ok(())
+    }
+
     #[test]
     fn next_board_state_reproduction() -> Result<()> {
         let s = r".....
@@ -288,7 +689,7 @@ mod tests {
 ##.##
 #.#.#
 .....";
-        let board = Board::try_from(s)?.next_board_state();
+        let board = Board::try_from(s)?.next_board_state_with(&Rule::conway());
         insta::assert_display_snapshot!(board, @r###"
         .....
         .....
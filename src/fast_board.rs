@@ -0,0 +1,228 @@
+use crate::board::{Board, Rule, State, Topology, NEIGHBORS};
+use std::convert::TryFrom;
+
+// Layout of a packed cell: bits 0-1 hold the cell's own state, and the eight
+// 2-bit fields at bits 2..18 mirror the state of each surrounding neighbor in
+// `NEIGHBORS` order. The resulting 18-bit value keys directly into a precomputed
+// transition table, so stepping is a lookup instead of a per-cell neighbor scan.
+const KEY_BITS: u32 = 18;
+const KEY_MASK: u32 = (1 << KEY_BITS) - 1;
+
+fn slot_shift(slot: usize) -> u32 {
+    2 + 2 * slot as u32
+}
+
+// The slot a cell occupies in its neighbor's packed field is the slot of the
+// opposite offset, e.g. we are the south neighbor of the cell to our north.
+fn opposite_slot(slot: usize) -> usize {
+    let (dy, dx) = NEIGHBORS[slot];
+    NEIGHBORS
+        .iter()
+        .position(|&(oy, ox)| oy == -dy && ox == -dx)
+        .expect("neighbor offsets are symmetric")
+}
+
+/// Bit-packed board that precomputes the transition rule into a lookup table and
+/// maintains each cell's neighborhood incrementally, avoiding the per-cell
+/// bounds-checked lookups the dense [`Board`] does.
+///
+/// Convert in and out with `FastBoard::from(&board)` / `Board::from(&fast)`.
+#[derive(Clone)]
+pub struct FastBoard {
+    width: usize,
+    height: usize,
+    topology: Topology,
+    cells: Vec<u32>,
+    // table[key] is the cell's next state (0 or 1) for an 18-bit neighborhood key.
+    table: Vec<u8>,
+}
+
+impl FastBoard {
+    /// Build a packed board from `board`, compiling `rule` into the lookup table.
+    pub fn new(board: &Board, rule: &Rule) -> Self {
+        let (width, height) = (board.width(), board.height());
+        let mut fast = Self {
+            width,
+            height,
+            topology: board.topology(),
+            cells: vec![0; width * height],
+            table: Self::compile(rule),
+        };
+        // Seed own-state bits, then derive every neighbor field from them.
+        for y in 0..height {
+            for x in 0..width {
+                if board.state_at(y, x) == State::ALIVE {
+                    fast.cells[y * width + x] |= 1;
+                }
+            }
+        }
+        for y in 0..height {
+            for x in 0..width {
+                let alive = fast.cells[y * width + x] & 1;
+                if alive == 0 {
+                    continue;
+                }
+                for slot in 0..NEIGHBORS.len() {
+                    if let Some((ny, nx)) = fast.neighbor(y, x, slot) {
+                        let shift = slot_shift(opposite_slot(slot));
+                        fast.cells[ny * width + nx] |= 1 << shift;
+                    }
+                }
+            }
+        }
+        fast
+    }
+
+    /// Precompute the next state for every possible 18-bit neighborhood key.
+    fn compile(rule: &Rule) -> Vec<u8> {
+        let mut table = vec![0u8; 1 << KEY_BITS];
+        for (key, slot) in table.iter_mut().enumerate() {
+            let current = State::from(key & 1 != 0);
+            let live = (0..NEIGHBORS.len())
+                .filter(|&s| (key >> slot_shift(s)) & 1 != 0)
+                .count();
+            *slot = (rule.apply(current, live) == State::ALIVE) as u8;
+        }
+        table
+    }
+
+    fn neighbor(&self, y: usize, x: usize, slot: usize) -> Option<(usize, usize)> {
+        let (dy, dx) = NEIGHBORS[slot];
+        match self.topology {
+            Topology::Bounded => {
+                let ny = usize::try_from(y as isize + dy).ok().filter(|&n| n < self.height)?;
+                let nx = usize::try_from(x as isize + dx).ok().filter(|&n| n < self.width)?;
+                Some((ny, nx))
+            }
+            Topology::Toroidal => {
+                if self.width == 0 || self.height == 0 {
+                    return None;
+                }
+                let ny = (y + self.height).wrapping_add_signed(dy) % self.height;
+                let nx = (x + self.width).wrapping_add_signed(dx) % self.width;
+                Some((ny, nx))
+            }
+        }
+    }
+
+    /// Advance one generation, flipping only the cells whose state changes and
+    /// patching the affected neighbor fields in place.
+    pub fn step(&mut self) {
+        let flips: Vec<(usize, usize, u8)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (y, x)))
+            .filter_map(|(y, x)| {
+                let idx = y * self.width + x;
+                let next = self.table[(self.cells[idx] & KEY_MASK) as usize];
+                (next != (self.cells[idx] & 1) as u8).then_some((y, x, next))
+            })
+            .collect();
+        for (y, x, next) in flips {
+            let idx = y * self.width + x;
+            self.cells[idx] = (self.cells[idx] & !1) | next as u32;
+            for slot in 0..NEIGHBORS.len() {
+                if let Some((ny, nx)) = self.neighbor(y, x, slot) {
+                    let shift = slot_shift(opposite_slot(slot));
+                    let nidx = ny * self.width + nx;
+                    self.cells[nidx] = (self.cells[nidx] & !(1 << shift)) | ((next as u32) << shift);
+                }
+            }
+        }
+    }
+}
+
+impl From<&Board> for FastBoard {
+    fn from(board: &Board) -> Self {
+        FastBoard::new(board, &Rule::conway())
+    }
+}
+
+impl From<&FastBoard> for Board {
+    fn from(fast: &FastBoard) -> Self {
+        let mut board = Board::new(fast.width, fast.height).with_topology(fast.topology);
+        for y in 0..fast.height {
+            for x in 0..fast.width {
+                let alive = fast.cells[y * fast.width + x] & 1 != 0;
+                board.set(y, x, State::from(alive));
+            }
+        }
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+
+    fn run_parity(input: &str, rule: &Rule, topology: Topology, generations: usize) -> Result<()> {
+        let mut dense = Board::try_from(input)?.with_topology(topology);
+        let mut fast = FastBoard::new(&dense, rule);
+        for _ in 0..generations {
+            dense = dense.next_board_state_with(rule);
+            fast.step();
+            assert_eq!(Board::from(&fast).to_string(), dense.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn matches_dense_blinker() -> Result<()> {
+        let s = r".....
+..#..
+..#..
+..#..
+.....";
+        run_parity(s, &Rule::conway(), Topology::Bounded, 3)
+    }
+
+    #[test]
+    fn matches_dense_toroidal_glider() -> Result<()> {
+        let s = r".#......
+..#.....
+###.....
+........
+........
+........
+........
+........";
+        run_parity(s, &Rule::conway(), Topology::Toroidal, 6)
+    }
+
+    #[test]
+    fn matches_dense_highlife() -> Result<()> {
+        let s = r".###.
+.#.#.
+.###.
+.....
+.....";
+        run_parity(s, &Rule::try_from("B36/S23")?, Topology::Bounded, 4)
+    }
+
+    // Rough benchmark of the packed path against the naive one. Run with
+    // `cargo test --release -- --ignored --nocapture bench_fast_vs_naive`.
+    #[test]
+    #[ignore]
+    fn bench_fast_vs_naive() {
+        use std::time::Instant;
+
+        let rule = Rule::conway();
+        let mut dense = Board::new(256, 256);
+        dense.random_state();
+
+        let start = Instant::now();
+        let mut naive = dense.clone();
+        for _ in 0..100 {
+            naive = naive.next_board_state_with(&rule);
+        }
+        let naive_elapsed = start.elapsed();
+
+        let start = Instant::now();
+        let mut fast = FastBoard::new(&dense, &rule);
+        for _ in 0..100 {
+            fast.step();
+        }
+        let fast_elapsed = start.elapsed();
+
+        println!("naive: {naive_elapsed:?}, fast: {fast_elapsed:?}");
+    }
+}
@@ -1,4 +1,6 @@
-use board::Board;
+use board::{Board, Neighborhood, Rule, Topology};
+use fast_board::FastBoard;
+use sparse_board::SparseBoard;
 use crossterm::{style, terminal, QueueableCommand};
 use std::{
     convert::TryFrom,
@@ -11,6 +13,8 @@ use std::{
 use structopt::StructOpt;
 
 mod board;
+mod fast_board;
+mod sparse_board;
 
 pub type Error = Box<dyn std::error::Error>;
 pub type Result<T> = std::result::Result<T, Error>;
@@ -28,10 +32,76 @@ struct Opt {
     /// Specify FPS
     #[structopt(short, long, default_value = "10")]
     fps: f64,
+    /// Transition rule in birth/survival notation, e.g. B3/S23 (Conway),
+    /// B36/S23 (Highlife), B2/S (Seeds)
+    #[structopt(short, long, default_value = "B3/S23")]
+    rule: Rule,
+    /// Edge topology: bounded (walls) or toroidal (wraps around)
+    #[structopt(short, long, default_value = "bounded")]
+    topology: Topology,
+    /// Neighbor strategy: moore (8-adjacent) or line-of-sight (ray-cast)
+    #[structopt(short, long, default_value = "moore")]
+    neighborhood: Neighborhood,
+    /// Use the bit-packed FastBoard stepping path
+    #[structopt(long)]
+    fast: bool,
+    /// Let the universe grow with the pattern, viewed through a fixed window
+    #[structopt(short, long)]
+    expand: bool,
+    /// Use the sparse HashSet-backed backend for mostly-empty universes
+    #[structopt(short, long)]
+    sparse: bool,
+}
+
+impl Opt {
+    /// Reject flag combinations the selected backend can't honor, so an option
+    /// is never silently discarded.
+    fn validate(&self) -> Result<()> {
+        let backends: Vec<&str> = [
+            (self.fast, "--fast"),
+            (self.sparse, "--sparse"),
+            (self.expand, "--expand"),
+        ]
+        .iter()
+        .filter_map(|&(on, name)| on.then_some(name))
+        .collect();
+        if backends.len() > 1 {
+            Err(format!("{} are mutually exclusive", backends.join(" and ")))?;
+        }
+
+        // The packed and sparse backends only implement the Moore neighborhood.
+        if (self.fast || self.sparse) && self.neighborhood != Neighborhood::Moore {
+            let backend = if self.fast { "--fast" } else { "--sparse" };
+            Err(format!(
+                "{backend} only supports --neighborhood moore"
+            ))?;
+        }
+        // The sparse backend lives in an unbounded coordinate space and has no
+        // wrapping edges to model a torus.
+        if self.sparse && self.topology != Topology::Bounded {
+            Err("--sparse only supports --topology bounded")?;
+        }
+        // Line-of-sight rays stop at the grid edge rather than wrapping, so they
+        // can't honor a toroidal topology.
+        if self.neighborhood == Neighborhood::LineOfSight && self.topology != Topology::Bounded {
+            Err("--neighborhood line-of-sight only supports --topology bounded")?;
+        }
+        // The expanding universe grows whenever a live cell reaches an outer
+        // line; a wrapping edge would make it grow forever, and line-of-sight's
+        // edge-bounded rays shift meaning as the grid resizes.
+        if self.expand && self.topology != Topology::Bounded {
+            Err("--expand only supports --topology bounded")?;
+        }
+        if self.expand && self.neighborhood != Neighborhood::Moore {
+            Err("--expand only supports --neighborhood moore")?;
+        }
+        Ok(())
+    }
 }
 
 fn main() -> Result<()> {
     let opt = Opt::from_args();
+    opt.validate()?;
 
     let mut board = match opt.input {
         Some(path) => Board::try_from(fs::read_to_string(path)?.as_str())?,
@@ -40,17 +110,62 @@ fn main() -> Result<()> {
             board.random_state();
             board
         }
-    };
+    }
+    .with_topology(opt.topology)
+    .with_neighborhood(opt.neighborhood);
 
     let mut stdout = stdout();
+    let frame = Duration::from_secs_f64(1. / opt.fps);
+
+    if opt.fast {
+        let mut fast = FastBoard::new(&board, &opt.rule);
+        loop {
+            stdout
+                .queue(terminal::Clear(terminal::ClearType::All))?
+                .queue(style::Print(Board::from(&fast)))?
+                .flush()?;
+
+            fast.step();
+
+            sleep(frame);
+        }
+    }
+
+    if opt.sparse {
+        let mut sparse = SparseBoard::from(&board);
+        loop {
+            stdout
+                .queue(terminal::Clear(terminal::ClearType::All))?
+                .queue(style::Print(Board::from(&sparse)))?
+                .flush()?;
+
+            sparse = sparse.next_board_state_with(&opt.rule);
+
+            sleep(frame);
+        }
+    }
+
+    if opt.expand {
+        loop {
+            stdout
+                .queue(terminal::Clear(terminal::ClearType::All))?
+                .queue(style::Print(board.viewport(opt.with, opt.height)))?
+                .flush()?;
+
+            board = board.next_board_state_expanding_with(&opt.rule);
+
+            sleep(frame);
+        }
+    }
+
     loop {
         stdout
             .queue(terminal::Clear(terminal::ClearType::All))?
             .queue(style::Print(&board))?
             .flush()?;
 
-        board = board.next_board_state();
+        board = board.next_board_state_with(&opt.rule);
 
-        sleep(Duration::from_secs_f64(1. / opt.fps));
+        sleep(frame);
     }
 }
@@ -0,0 +1,115 @@
+use crate::board::{Board, Rule, State, NEIGHBORS};
+use std::collections::{HashMap, HashSet};
+
+/// Live-cell-only board for huge, mostly-empty universes.
+///
+/// Only the coordinates `(x, y)` of live cells are stored, so memory and step
+/// cost scale with the live population rather than the grid area, and the
+/// coordinate space is effectively unbounded. Convert to and from a dense
+/// [`Board`] with the [`From`] impls.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SparseBoard {
+    live: HashSet<(i64, i64)>,
+}
+
+impl SparseBoard {
+    /// Step forward under an arbitrary [`Rule`], visiting only live cells and
+    /// their neighbors.
+    pub fn next_board_state_with(&self, rule: &Rule) -> Self {
+        // Tally how many live cells border each coordinate.
+        let mut counts: HashMap<(i64, i64), usize> = HashMap::new();
+        for &(x, y) in &self.live {
+            for &(dy, dx) in NEIGHBORS.iter() {
+                *counts.entry((x + dx as i64, y + dy as i64)).or_insert(0) += 1;
+            }
+        }
+        // Every live cell is a candidate even with zero live neighbors, so rules
+        // with an `S0` survival clause behave correctly.
+        let candidates = counts.keys().copied().chain(self.live.iter().copied());
+        let mut live = HashSet::new();
+        for coord in candidates {
+            let current = State::from(self.live.contains(&coord));
+            let n = counts.get(&coord).copied().unwrap_or(0);
+            if rule.apply(current, n) == State::ALIVE {
+                live.insert(coord);
+            }
+        }
+        Self { live }
+    }
+}
+
+impl From<&Board> for SparseBoard {
+    fn from(board: &Board) -> Self {
+        let (ox, oy) = board.origin();
+        let mut live = HashSet::new();
+        for y in 0..board.height() {
+            for x in 0..board.width() {
+                if board.state_at(y, x) == State::ALIVE {
+                    live.insert((ox + x as i64, oy + y as i64));
+                }
+            }
+        }
+        Self { live }
+    }
+}
+
+impl From<&SparseBoard> for Board {
+    fn from(sparse: &SparseBoard) -> Self {
+        let (x0, y0, x1, y1) = match sparse.live.iter().copied().fold(None, |acc, (x, y)| {
+            Some(match acc {
+                Some((x0, y0, x1, y1)) => (x.min(x0), y.min(y0), x.max(x1), y.max(y1)),
+                None => (x, y, x, y),
+            })
+        }) {
+            Some(bounds) => bounds,
+            None => return Board::new(0, 0),
+        };
+        let w = (x1 - x0 + 1) as usize;
+        let h = (y1 - y0 + 1) as usize;
+        let mut board = Board::new(w, h);
+        for &(x, y) in &sparse.live {
+            board.set((y - y0) as usize, (x - x0) as usize, State::ALIVE);
+        }
+        board
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn blinker_oscillates() -> Result<()> {
+        let board = Board::try_from("###")?;
+        let sparse = SparseBoard::from(&board);
+        let next = sparse.next_board_state_with(&Rule::conway());
+        // A horizontal blinker becomes a vertical one.
+        assert_eq!(Board::from(&next).to_string(), "#\n#\n#\n");
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips_through_dense() -> Result<()> {
+        let board = Board::try_from("#.#\n.#.\n#.#")?;
+        let sparse = SparseBoard::from(&board);
+        assert_eq!(sparse.live.len(), 5);
+        assert_eq!(Board::from(&sparse).to_string(), board.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn matches_dense_stepping() -> Result<()> {
+        // Pad so the glider never reaches the dense wall over these generations.
+        let s = ".......\n.#.....\n..#....\n###....\n.......\n.......\n.......";
+        let mut dense = Board::try_from(s)?;
+        let mut sparse = SparseBoard::from(&dense);
+        for _ in 0..4 {
+            dense = dense.next_board_state_with(&Rule::conway());
+            sparse = sparse.next_board_state_with(&Rule::conway());
+            assert_eq!(sparse, SparseBoard::from(&dense));
+        }
+        Ok(())
+    }
+}